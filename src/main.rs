@@ -1,18 +1,59 @@
 use futures::stream::StreamExt;
+use hdrhistogram::Histogram;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use scylla::{
-    host_filter::AllowListHostFilter, prepared_statement::PreparedStatement,
-    statement::Consistency, transport::errors::QueryError, QueryResult, Session, SessionBuilder,
+    frame::value::CqlTimeuuid, host_filter::AllowListHostFilter,
+    prepared_statement::PreparedStatement, statement::Consistency,
+    transport::errors::QueryError, QueryResult, Session, SessionBuilder,
 };
 use std::{
+    collections::{HashMap, HashSet},
     io::Write,
     sync::{
-        atomic::{AtomicBool, Ordering},
-        Arc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
     },
     time::Duration,
     time::Instant,
 };
 
+// `cdc$operation` values, see https://opensource.docs.scylladb.com/stable/cdc/cdc-log-table.html
+const CDC_OPERATION_UPDATE: i8 = 1;
+const CDC_OPERATION_INSERT: i8 = 2;
+const CDC_OPERATION_ROW_DELETE: i8 = 3;
+
+// Number of 100ns intervals between the UUID epoch (1582-10-15) and the unix epoch (1970-01-01).
+const UUID_TO_UNIX_100NS_INTERVALS: i64 = 0x01B2_1DD2_1381_4000;
+
+// How far behind "now" the CDC watermark is kept, to give rows in other cdc$stream_id
+// partitions time to become visible before we'd otherwise consider them stale.
+const CDC_WATERMARK_SAFETY_MARGIN_NANOS: i64 = 5_000_000_000;
+
+fn unix_now_nanos() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as i64
+}
+
+// Converts a CDC log `cdc$time` timeuuid into a unix timestamp in nanoseconds,
+// so that it can be used as a plain, monotonically increasing watermark.
+fn cdc_time_to_unix_nanos(time: CqlTimeuuid) -> i64 {
+    let uuid: uuid::Uuid = time.into();
+    let (ticks, _counter) = uuid.get_timestamp().unwrap().to_rfc4122();
+    (ticks as i64 - UUID_TO_UNIX_100NS_INTERVALS) * 100
+}
+
+// The inverse of `cdc_time_to_unix_nanos`: the smallest timeuuid at (or just above) the given
+// unix timestamp, used as an exclusive lower bound so a poll only asks for rows added since the
+// last one, instead of re-reading and re-filtering the whole CDC log client-side every time.
+fn unix_nanos_to_min_cdc_timeuuid(unix_nanos: i64) -> CqlTimeuuid {
+    let hundred_ns_ticks = (unix_nanos.max(0) / 100) as u64 + UUID_TO_UNIX_100NS_INTERVALS as u64;
+    let timestamp = uuid::Timestamp::from_rfc4122(hundred_ns_ticks, 0);
+    CqlTimeuuid::from(uuid::Uuid::new_v1(timestamp, &[0; 6]))
+}
+
 async fn create_session(node_addr: &str) -> Arc<Session> {
     let session: Session = SessionBuilder::new()
         .known_node(node_addr)
@@ -56,7 +97,8 @@ async fn setup_keyspace_table_and_view(session: &Session) {
 
     session
         .query(
-            "CREATE TABLE view_test.tab (p int, c int, r int, primary key (p, c))",
+            "CREATE TABLE view_test.tab (p int, c int, r int, primary key (p, c)) \
+             WITH cdc = {'enabled': true}",
             (),
         )
         .await
@@ -72,27 +114,226 @@ async fn setup_keyspace_table_and_view(session: &Session) {
         .unwrap();
 }
 
+// Latency and throughput stats for the write workload, shared across all writer fibers.
+struct WriteStats {
+    latencies_us: Mutex<Histogram<u64>>,
+    successful: AtomicU64,
+    retried: AtomicU64,
+    failed: AtomicU64,
+    start: Instant,
+}
+
+impl WriteStats {
+    fn new() -> WriteStats {
+        WriteStats {
+            latencies_us: Mutex::new(Histogram::new(3).unwrap()),
+            successful: AtomicU64::new(0),
+            retried: AtomicU64::new(0),
+            failed: AtomicU64::new(0),
+            start: Instant::now(),
+        }
+    }
+
+    fn record_success(&self, latency: Duration) {
+        let latency_us = latency.as_micros().min(u64::MAX as u128) as u64;
+        self.latencies_us.lock().unwrap().record(latency_us).unwrap();
+        self.successful.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn report(&self) -> String {
+        let hist = self.latencies_us.lock().unwrap();
+        let successful = self.successful.load(Ordering::Relaxed);
+        let retried = self.retried.load(Ordering::Relaxed);
+        let failed = self.failed.load(Ordering::Relaxed);
+        let rate = successful as f64 / self.start.elapsed().as_secs_f64().max(f64::EPSILON);
+
+        format!(
+            "latency us: p50={} p90={} p99={} max={} | writes: {successful} successful, \
+             {retried} retried, {failed} failed | {rate:.0} writes/sec",
+            hist.value_at_percentile(50.0),
+            hist.value_at_percentile(90.0),
+            hist.value_at_percentile(99.0),
+            hist.max(),
+        )
+    }
+}
+
 struct WritesStopper {
     should_stop: Arc<AtomicBool>,
+    stats: Arc<WriteStats>,
 }
 
 impl WritesStopper {
-    pub fn new(should_stop: Arc<AtomicBool>) -> WritesStopper {
-        WritesStopper { should_stop }
+    pub fn new(should_stop: Arc<AtomicBool>, stats: Arc<WriteStats>) -> WritesStopper {
+        WritesStopper { should_stop, stats }
     }
 
     pub fn stop(&self) {
         self.should_stop.store(true, Ordering::Relaxed);
+        print_msg(&format!("Final write stats: {}", self.stats.report()));
+    }
+}
+
+// A single (stop, restart) event performed by the ChaosDriver against one node.
+#[derive(Clone, Copy, Debug)]
+struct ChaosEvent {
+    node_id: usize,
+    down_at: Instant,
+    up_at: Instant,
+}
+
+// Drives scripted node failures by shelling out to a configurable stop/start command per node,
+// so that crash/restart scenarios are reproducible instead of relying on a human operator.
+struct ChaosDriver {
+    should_stop: Arc<AtomicBool>,
+    timeline: Arc<Mutex<Vec<ChaosEvent>>>,
+    task: Mutex<Option<tokio::task::JoinHandle<()>>>,
+}
+
+impl ChaosDriver {
+    // `stop_command`/`start_command` receive the 0-based node index and return the shell
+    // command to run against it, e.g. `docker kill node{id}` / `docker start node{id}`.
+    fn start(
+        node_count: usize,
+        stop_command: impl Fn(usize) -> String + Send + 'static,
+        start_command: impl Fn(usize) -> String + Send + 'static,
+        dwell_time: Duration,
+        pause_between_outages: Duration,
+    ) -> ChaosDriver {
+        let should_stop = Arc::new(AtomicBool::new(false));
+        let timeline = Arc::new(Mutex::new(Vec::new()));
+
+        let task_should_stop = should_stop.clone();
+        let task_timeline = timeline.clone();
+
+        let task = tokio::spawn(async move {
+            loop {
+                // Checked both before and after the pause: if no outage is in progress when
+                // `stop()` is called, we return right away instead of waiting out a full
+                // `pause_between_outages` first.
+                if task_should_stop.load(Ordering::Relaxed) {
+                    return;
+                }
+                let pause_end = Instant::now() + pause_between_outages;
+                while Instant::now() < pause_end && !task_should_stop.load(Ordering::Relaxed) {
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                }
+                if task_should_stop.load(Ordering::Relaxed) {
+                    return;
+                }
+
+                let node_id = rand::thread_rng().gen_range(0..node_count);
+
+                print_msg(&format!("ChaosDriver: stopping node #{node_id}"));
+                run_shell_command(&stop_command(node_id)).await;
+                let down_at = Instant::now();
+
+                tokio::time::sleep(dwell_time).await;
+
+                print_msg(&format!("ChaosDriver: restarting node #{node_id}"));
+                run_shell_command(&start_command(node_id)).await;
+                let up_at = Instant::now();
+
+                task_timeline.lock().unwrap().push(ChaosEvent {
+                    node_id,
+                    down_at,
+                    up_at,
+                });
+            }
+        });
+
+        ChaosDriver {
+            should_stop,
+            timeline,
+            task: Mutex::new(Some(task)),
+        }
+    }
+
+    // Signals the driver to stop and waits for it to do so. Since the stop check only happens
+    // between outages, this blocks until any in-progress outage has had its node restarted,
+    // so callers never observe a node that's still down because chaos was "stopped" mid-outage.
+    async fn stop(&self) {
+        self.should_stop.store(true, Ordering::Relaxed);
+
+        let task = self.task.lock().unwrap().take();
+        if let Some(task) = task {
+            let _ = task.await;
+        }
+    }
+
+    // Returns the outages recorded so far for `node_id` that ended at or after `since`,
+    // i.e. outages that could plausibly explain a mismatch observed after `since`.
+    fn outages_since(&self, node_id: usize, since: Instant) -> Vec<ChaosEvent> {
+        self.timeline
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|event| event.node_id == node_id && event.up_at >= since)
+            .copied()
+            .collect()
+    }
+}
+
+async fn run_shell_command(command: &str) {
+    let status = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .status()
+        .await;
+
+    match status {
+        Ok(status) if status.success() => {}
+        Ok(status) => {
+            print_msg(&format!("ChaosDriver: command `{command}` exited with {status}"))
+        }
+        Err(err) => print_msg(&format!("ChaosDriver: failed to run `{command}`: {err}")),
     }
 }
 
-// Starts <concurrency> fibers which perform an insert every <frequency_interval> seconds.
-// The writes will stop after calling WritesStopper::stop().
-async fn start_writes(session: &Arc<Session>) -> WritesStopper {
+// Relative weights used to pick the next operation a writer fiber performs.
+struct WriteOpWeights {
+    insert: u32,
+    update: u32,
+    delete: u32,
+}
+
+enum WriteOp {
+    // Insert a brand new (p, c) key.
+    Insert,
+    // Re-insert an existing (p, c) key with a new `r`, exercising the view's update path.
+    Update,
+    // Delete an existing (p, c) key, exercising the view's delete path.
+    Delete,
+}
+
+fn choose_write_op(rng: &mut impl Rng, have_existing_keys: bool, weights: &WriteOpWeights) -> WriteOp {
+    if !have_existing_keys {
+        return WriteOp::Insert;
+    }
+
+    let roll = rng.gen_range(0..(weights.insert + weights.update + weights.delete));
+    if roll < weights.insert {
+        WriteOp::Insert
+    } else if roll < weights.insert + weights.update {
+        WriteOp::Update
+    } else {
+        WriteOp::Delete
+    }
+}
+
+// Starts <concurrency> fibers which perform a write every <frequency_interval> seconds, mixing
+// fresh inserts with updates and deletes of already-written keys according to `weights`. `seed`
+// seeds every fiber's RNG (value/payload generation and operation choice), so a failing run can
+// be replayed deterministically by passing the same seed again. Each fiber only ever
+// updates/deletes keys it wrote itself (fibers never share a key, since `id + i * concurrency`
+// is unique per fiber), so the live key set is kept fiber-local instead of behind a shared lock.
+async fn start_writes(session: &Arc<Session>, seed: u64, weights: WriteOpWeights) -> WritesStopper {
     let concurrency = 512;
     let frequency_interval = Duration::from_millis(100);
 
     let should_stop: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+    let stats: Arc<WriteStats> = Arc::new(WriteStats::new());
+    let weights = Arc::new(weights);
     let report_gap = Duration::from_secs(4);
 
     let mut insert: PreparedStatement = session
@@ -102,14 +343,28 @@ async fn start_writes(session: &Arc<Session>) -> WritesStopper {
     insert.set_is_idempotent(true);
     let insert = Arc::new(insert);
 
-    // Start <concurrency> fibers which perform an insert every <frequency_interval> seconds.
+    let mut delete: PreparedStatement = session
+        .prepare("DELETE FROM view_test.tab WHERE p = ? AND c = ?")
+        .await
+        .unwrap();
+    delete.set_is_idempotent(true);
+    let delete = Arc::new(delete);
+
+    // Start <concurrency> fibers which perform a write every <frequency_interval> seconds.
     for id in 0..concurrency {
         let session = session.clone();
         let insert = insert.clone();
+        let delete = delete.clone();
+        let weights = weights.clone();
 
         let should_stop = should_stop.clone();
+        let stats = stats.clone();
 
         tokio::spawn(async move {
+            let mut rng = ChaCha8Rng::seed_from_u64(seed.wrapping_add(id as u64));
+            // Keys written by this fiber and not yet deleted; never touched by other fibers.
+            let mut live_keys: Vec<(i32, i32)> = Vec::new();
+
             // Print the first report after 1 second.
             let mut last_report = Instant::now() - report_gap + Duration::from_secs(1);
 
@@ -126,34 +381,72 @@ async fn start_writes(session: &Arc<Session>) -> WritesStopper {
                 // The first fiber reports the total number of requests sent every ~<report_gap> seconds.
                 if id == 0 && last_report.elapsed() > report_gap {
                     println!(
-                        "Wrote ~{} rows... Press Enter to stop the writes and verify.",
-                        i * concurrency
+                        "Sent ~{} writes... ({}) Press Enter to stop the writes and verify.",
+                        i * concurrency,
+                        stats.report()
                     );
                     last_report = std::time::Instant::now();
                 }
 
-                // Perform the insert
-                let value = id + i * concurrency;
+                // Pick an existing key to update/delete from the keys this fiber has written so far.
+                let existing_key = (!live_keys.is_empty())
+                    .then(|| live_keys[rng.gen_range(0..live_keys.len())]);
+
+                let op = choose_write_op(&mut rng, existing_key.is_some(), &weights);
+
+                let (p, c, new_key) = match &op {
+                    WriteOp::Insert => {
+                        let value = id as i32 + i as i32 * concurrency as i32;
+                        (value, value, Some((value, value)))
+                    }
+                    WriteOp::Update => {
+                        let (p, c) = existing_key.unwrap();
+                        (p, c, None)
+                    }
+                    WriteOp::Delete => {
+                        let (p, c) = existing_key.unwrap();
+                        live_keys.retain(|&key| key != (p, c));
+                        (p, c, None)
+                    }
+                };
+
+                // Drawn once per write, so retries don't shift the RNG sequence.
+                let r = rng.gen::<i32>();
+
                 let tries_num = 8;
+                let op_start = Instant::now();
                 for try_number in 1.. {
-                    let result: Result<QueryResult, QueryError> =
-                        session.execute(&insert, (value, value, value)).await;
+                    let result: Result<QueryResult, QueryError> = match &op {
+                        WriteOp::Delete => session.execute(&delete, (p, c)).await,
+                        WriteOp::Insert | WriteOp::Update => {
+                            session.execute(&insert, (p, c, r)).await
+                        }
+                    };
 
                     match &result {
-                        Ok(_) => break,
+                        Ok(_) => {
+                            stats.record_success(op_start.elapsed());
+                            break;
+                        }
                         Err(_) if try_number < tries_num => {
+                            stats.retried.fetch_add(1, Ordering::Relaxed);
                             tokio::time::sleep(Duration::from_millis(64)).await;
                         }
                         Err(_) => {
+                            stats.failed.fetch_add(1, Ordering::Relaxed);
                             let _ = result.unwrap();
                         }
                     }
                 }
+
+                if let Some(key) = new_key {
+                    live_keys.push(key);
+                }
             }
         });
     }
 
-    WritesStopper::new(should_stop)
+    WritesStopper::new(should_stop, stats)
 }
 
 async fn read_all_rows_from_table(session: &Session, table: &str) -> Vec<(i32, i32, i32)> {
@@ -178,6 +471,194 @@ async fn read_all_rows_from_table(session: &Session, table: &str) -> Vec<(i32, i
     rows
 }
 
+// Retries on transient errors (e.g. a node that's still mid-restart from a ChaosDriver
+// outage) instead of failing the whole tool over what's usually just a few seconds
+// of unavailability.
+async fn count_rows(session: &Session, table: &str) -> i64 {
+    let mut select: PreparedStatement = session
+        .prepare(format!("SELECT COUNT(*) FROM view_test.{table}"))
+        .await
+        .unwrap();
+    select.set_is_idempotent(true);
+
+    // Generous enough (~60s) to ride out a ChaosDriver-restarted node that's still booting,
+    // not just a single dropped connection.
+    let tries_num = 60;
+    for try_number in 1.. {
+        match session.execute(&select, ()).await {
+            Ok(query_result) => return query_result.single_row_typed::<(i64,)>().unwrap().0,
+            Err(_) if try_number < tries_num => {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+            Err(err) => panic!("count_rows({table}) failed after {tries_num} tries: {err}"),
+        }
+    }
+
+    unreachable!()
+}
+
+// Polls the base table row count (CL=QUORUM) against each node's view row count (CL=ONE)
+// every `poll_interval`, and declares convergence once they've all matched for
+// `required_consecutive_matches` polls in a row. Replaces a fixed settle-time sleep with
+// a real measurement of view build/repair latency.
+async fn wait_for_convergence(session: &Session, node_sessions: &[Arc<Session>]) {
+    let poll_interval = Duration::from_secs(2);
+    let required_consecutive_matches = 3;
+    let timeout = Duration::from_secs(300);
+
+    let start = Instant::now();
+    let mut consecutive_matches = 0;
+
+    loop {
+        if start.elapsed() > timeout {
+            print_msg(&format!(
+                "WARNING: gave up waiting for convergence after {:?}",
+                start.elapsed()
+            ));
+            return;
+        }
+
+        let base_count = count_rows(session, "tab").await;
+
+        let mut view_counts: Vec<i64> = Vec::with_capacity(node_sessions.len());
+        for node_session in node_sessions {
+            view_counts.push(count_rows(node_session, "tab_view").await);
+        }
+
+        if view_counts.iter().all(|&count| count == base_count) {
+            consecutive_matches += 1;
+            if consecutive_matches >= required_consecutive_matches {
+                print_msg(&format!(
+                    "Cluster converged in {:?} ({base_count} rows)",
+                    start.elapsed()
+                ));
+                return;
+            }
+        } else {
+            consecutive_matches = 0;
+            print_msg(&format!(
+                "Waiting for convergence... base: {base_count}, views: {view_counts:?}"
+            ));
+        }
+
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+// Tracks the set of rows that should currently be present in the view,
+// built by replaying the base table's CDC log instead of re-reading the whole table.
+struct ExpectedView {
+    last_read: i64,
+    rows: HashMap<(i32, i32), i32>,
+}
+
+impl ExpectedView {
+    fn new() -> ExpectedView {
+        ExpectedView {
+            last_read: i64::MIN,
+            rows: HashMap::new(),
+        }
+    }
+
+    // Reads every change recorded in `tab`'s CDC log since the last call and applies
+    // them in timestamp order. The `last_read` watermark only advances past changes older
+    // than `CDC_WATERMARK_SAFETY_MARGIN_NANOS`, so newer ones are read and applied again
+    // on the next poll (see below).
+    // Bounded by `cdc$time > ?` so a poll only pulls rows added since the last one, instead of
+    // re-transferring the whole CDC log accumulated since the run started. Note this still
+    // costs Scylla a per-partition (per cdc$stream_id) scan to apply the filter, since a CDC log
+    // read isn't restricted to a single stream here - it only saves the client-side re-read and
+    // re-parse of rows we've already consumed.
+    async fn poll(&mut self, session: &Session) {
+        let mut select: PreparedStatement = session
+            .prepare(
+                "SELECT cdc$time, cdc$operation, p, c, r FROM view_test.tab_scylla_cdc_log \
+                 WHERE cdc$time > ? ALLOW FILTERING",
+            )
+            .await
+            .unwrap();
+        select.set_is_idempotent(true);
+
+        let since = unix_nanos_to_min_cdc_timeuuid(self.last_read);
+        let mut changes: Vec<(i64, i8, i32, i32, Option<i32>)> = Vec::new();
+
+        let mut rows_iter = session
+            .execute_iter(select, (since,))
+            .await
+            .unwrap()
+            .into_typed::<(CqlTimeuuid, i8, i32, i32, Option<i32>)>();
+        while let Some(row) = rows_iter.next().await {
+            let (time, operation, p, c, r) = row.unwrap();
+            let time_nanos = cdc_time_to_unix_nanos(time);
+            if time_nanos > self.last_read {
+                changes.push((time_nanos, operation, p, c, r));
+            }
+        }
+
+        changes.sort_by_key(|(time, ..)| *time);
+
+        // The CDC log is partitioned by cdc$stream_id, so there's no global ordering across
+        // streams: a row can still show up in another partition with an earlier cdc$time than
+        // one we've already seen. Never advance the watermark past `now - margin`, so such a
+        // row is re-read (harmlessly, applying it again is idempotent) instead of being
+        // permanently skipped by the `cdc$time > ?` bound on the next poll.
+        let safe_watermark_cutoff = unix_now_nanos() - CDC_WATERMARK_SAFETY_MARGIN_NANOS;
+
+        for (time_nanos, operation, p, c, r) in changes {
+            match operation {
+                CDC_OPERATION_INSERT | CDC_OPERATION_UPDATE => {
+                    if let Some(r) = r {
+                        self.rows.insert((p, c), r);
+                    }
+                }
+                CDC_OPERATION_ROW_DELETE => {
+                    self.rows.remove(&(p, c));
+                }
+                _ => {}
+            }
+
+            if time_nanos <= safe_watermark_cutoff {
+                self.last_read = self.last_read.max(time_nanos);
+            }
+        }
+    }
+
+    fn as_set(&self) -> HashSet<(i32, i32, i32)> {
+        self.rows.iter().map(|(&(p, c), &r)| (p, c, r)).collect()
+    }
+}
+
+// Compares the expected view (built from CDC) against what a node's view table actually
+// contains, returning the (p, c, r) rows that are missing from the view and the ones that
+// are stale (present in the view but not expected). A key whose `r` differs shows up in both.
+fn diff_view(
+    expected: &HashSet<(i32, i32, i32)>,
+    view_rows: &[(i32, i32, i32)],
+) -> (Vec<(i32, i32, i32)>, Vec<(i32, i32, i32)>) {
+    let view: HashSet<(i32, i32, i32)> = view_rows.iter().copied().collect();
+
+    let mut missing: Vec<(i32, i32, i32)> = expected.difference(&view).copied().collect();
+    let mut stale: Vec<(i32, i32, i32)> = view.difference(expected).copied().collect();
+    missing.sort();
+    stale.sort();
+
+    (missing, stale)
+}
+
+// Reads a `--<name> <value>` pair from the CLI args, falling back to `default` if absent.
+fn parse_flag<T: std::str::FromStr>(name: &str, default: T) -> T {
+    std::env::args()
+        .collect::<Vec<_>>()
+        .windows(2)
+        .find(|window| window[0] == name)
+        .map(|window| {
+            window[1]
+                .parse()
+                .unwrap_or_else(|_| panic!("{name} expects a valid value"))
+        })
+        .unwrap_or(default)
+}
+
 fn print_msg(message: &str) {
     println!("{}", message);
     std::io::stdout().flush().unwrap();
@@ -211,42 +692,125 @@ async fn main() {
 
     setup_keyspace_table_and_view(&session).await;
 
-    print_msg(
-        "Starting the writes, please kill and restart one node while the writes are being sent.",
-    );
-    let writes_stopper: WritesStopper = start_writes(&session).await;
+    // Pass --chaos to have the tool crash/restart a random node itself instead of
+    // relying on a human operator, for reproducible runs.
+    let chaos_enabled = std::env::args().any(|arg| arg == "--chaos");
+
+    // Pass --seed <n> to replay a previous run's exact sequence of writes.
+    let default_seed = || {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64
+    };
+    let seed: u64 = parse_flag("--seed", default_seed());
+
+    // Pass --insert-weight/--update-weight/--delete-weight to change the write op mix.
+    let weights = WriteOpWeights {
+        insert: parse_flag("--insert-weight", 70),
+        update: parse_flag("--update-weight", 20),
+        delete: parse_flag("--delete-weight", 10),
+    };
+    print_msg(&format!("Using write workload seed: {seed}"));
+
+    // Track outages from the moment chaos/writes actually start, so the first verification
+    // pass can still be correlated against an outage that happened before it ran.
+    let run_started_at = Instant::now();
+
+    // Pass --chaos-stop-command/--chaos-start-command to control how a node is actually
+    // killed/restarted; "{node}" is replaced with the node's address (e.g. "127.0.0.2:9042").
+    // The defaults are placeholders - point them at whatever manages your cluster's nodes
+    // (docker, systemctl over ssh, etc.), since that's inherently environment-specific.
+    let chaos_stop_command: String =
+        parse_flag("--chaos-stop-command", "echo 'no --chaos-stop-command configured for {node}'".to_string());
+    let chaos_start_command: String =
+        parse_flag("--chaos-start-command", "echo 'no --chaos-start-command configured for {node}'".to_string());
+
+    let chaos_driver: Option<ChaosDriver> = if chaos_enabled {
+        print_msg("ChaosDriver enabled: a random node will be killed and restarted periodically.");
+        let node_addrs: Vec<String> = nodes.iter().map(|addr| addr.to_string()).collect();
+        let stop_node_addrs = node_addrs.clone();
+        let start_node_addrs = node_addrs;
+        Some(ChaosDriver::start(
+            nodes.len(),
+            move |node_id| chaos_stop_command.replace("{node}", &stop_node_addrs[node_id]),
+            move |node_id| chaos_start_command.replace("{node}", &start_node_addrs[node_id]),
+            Duration::from_secs(20),
+            Duration::from_secs(30),
+        ))
+    } else {
+        print_msg(
+            "Starting the writes, please kill and restart one node while the writes are being sent.",
+        );
+        None
+    };
+
+    let writes_stopper: WritesStopper = start_writes(&session, seed, weights).await;
 
     std::io::stdin().read_line(&mut String::new()).unwrap();
 
     writes_stopper.stop();
-    print_msg("Stopped the writes, let's wait 60s for the cluster to settle.");
+    if let Some(chaos_driver) = &chaos_driver {
+        chaos_driver.stop().await;
+    }
+    print_msg("Stopped the writes, waiting for the cluster to converge.");
+
+    wait_for_convergence(&session, node_sessions).await;
 
-    tokio::time::sleep(Duration::from_secs(60)).await;
+    let mut expected_view = ExpectedView::new();
+    let mut last_verification_at = run_started_at;
 
     loop {
         print_msg("Verifying view table integrity...");
-        // Read all rows form the base table using CL=QUORUM
-        let base_rows: Vec<(i32, i32, i32)> = read_all_rows_from_table(&session, "tab").await;
+        // Replay the base table's CDC log to know exactly what the view should contain.
+        expected_view.poll(&session).await;
+        let expected_rows: HashSet<(i32, i32, i32)> = expected_view.as_set();
 
         for (node_id, single_node_session) in node_sessions.iter().enumerate() {
             // Read all view rows from this node using CL=ONE
             let view_rows: Vec<(i32, i32, i32)> =
                 read_all_rows_from_table(&single_node_session, "tab_view").await;
 
-            if view_rows == base_rows {
+            let (missing, stale) = diff_view(&expected_rows, &view_rows);
+
+            if missing.is_empty() && stale.is_empty() {
                 print_msg(&format!("View from node #{node_id} matches the base table"));
             } else {
                 print_msg(&format!(
                     "ERROR: View from node #{node_id} doesn't match the base table"
                 ));
-                print_msg(&format!(
-                    "base_rows.len(): {}, view_rows.len(): {}",
-                    base_rows.len(),
-                    view_rows.len(),
-                ));
+                if !missing.is_empty() {
+                    print_msg(&format!(
+                        "  missing from view ({} rows): {:?}",
+                        missing.len(),
+                        missing
+                    ));
+                }
+                if !stale.is_empty() {
+                    print_msg(&format!(
+                        "  stale in view ({} rows): {:?}",
+                        stale.len(),
+                        stale
+                    ));
+                }
+
+                if let Some(chaos_driver) = &chaos_driver {
+                    let outages = chaos_driver.outages_since(node_id, last_verification_at);
+                    if outages.is_empty() {
+                        print_msg("  no recorded outage of this node coincides with the mismatch");
+                    } else {
+                        for outage in outages {
+                            print_msg(&format!(
+                                "  coincides with outage of node #{node_id}: down for {:?}",
+                                outage.up_at - outage.down_at
+                            ));
+                        }
+                    }
+                }
             }
         }
 
+        last_verification_at = Instant::now();
         print_msg("\nThe check will be repeated after 60s");
         tokio::time::sleep(Duration::from_secs(60)).await;
     }